@@ -0,0 +1,138 @@
+use crate::{config::BbtConfig, rating::BbtRating};
+
+/// Calculates the new Bradley-Terry ratings of every player across multiple teams, for team
+/// and multiplayer games that the two-player [`crate::elo`] and [`crate::glicko2`] functions
+/// cannot express.
+///
+/// Takes in the teams that played a game, ordered from first to last place, and a
+/// [`BbtConfig`]. Each team is a `Vec` of the [`BbtRating`]s of its members.
+///
+/// A team's strength is modeled as the sum of its members' skill means, with a combined
+/// variance equal to the sum of their individual variances. Every pair of teams contributes a
+/// pairwise win probability (the logistic of their strength difference, scaled by their
+/// combined uncertainty and [`BbtConfig::beta`]); each player is then updated by their share of
+/// that surprise, proportional to how much of their team's uncertainty they contribute.
+///
+/// # Example
+/// ```
+/// use skillratings::{bbt::bbt, rating::BbtRating, config::BbtConfig};
+///
+/// let team_one = vec![BbtRating::new(), BbtRating::new()];
+/// let team_two = vec![BbtRating::new(), BbtRating::new()];
+///
+/// let new_ratings = bbt(&[team_one, team_two], &BbtConfig::new());
+///
+/// // The winning team's first-listed player should have gained rating.
+/// assert!(new_ratings[0][0].mu > BbtRating::new().mu);
+/// // The losing team's first-listed player should have lost rating.
+/// assert!(new_ratings[1][0].mu < BbtRating::new().mu);
+/// ```
+///
+/// # More
+/// [Weng & Lin, "A Bayesian Approximation Method for Online Ranking"](https://www.csie.ntu.edu.tw/~cjlin/papers/online_ranking/online_journal.pdf).
+#[must_use]
+pub fn bbt(teams: &[Vec<BbtRating>], config: &BbtConfig) -> Vec<Vec<BbtRating>> {
+    let team_mus: Vec<f64> = teams
+        .iter()
+        .map(|team| team.iter().map(|p| p.mu).sum())
+        .collect();
+    let team_variances: Vec<f64> = teams
+        .iter()
+        .map(|team| team.iter().map(|p| p.sigma.powi(2)).sum())
+        .collect();
+
+    teams
+        .iter()
+        .enumerate()
+        .map(|(i, team)| {
+            let mut delta = 0.0;
+            let mut eta = 0.0;
+
+            for (j, _) in teams.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                let c = (team_variances[i] + team_variances[j] + 2.0 * config.beta.powi(2)).sqrt();
+                let expected = 1.0 / (1.0 + (-(team_mus[i] - team_mus[j]) / c).exp());
+                // Position in the slice is the final placement: a lower index beat a higher one.
+                let score = if i < j { 1.0 } else { 0.0 };
+
+                delta += (team_variances[i] / c) * (score - expected);
+                eta += (team_variances[i] / c).powi(2) * expected * (1.0 - expected);
+            }
+
+            team.iter()
+                .map(|player| {
+                    let share = player.sigma.powi(2) / team_variances[i];
+                    let new_mu = delta.mul_add(share, player.mu);
+                    let new_variance = (player.sigma.powi(2) * (1.0 - eta * share))
+                        .max(player.sigma.powi(2) * config.kappa)
+                        + config.tau.powi(2);
+
+                    BbtRating {
+                        mu: new_mu,
+                        sigma: new_variance.sqrt(),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bbt_two_teams() {
+        let team_one = vec![BbtRating::new(), BbtRating::new()];
+        let team_two = vec![BbtRating::new(), BbtRating::new()];
+
+        let new_ratings = bbt(&[team_one, team_two], &BbtConfig::new());
+
+        assert_eq!(new_ratings.len(), 2);
+        assert_eq!(new_ratings[0].len(), 2);
+
+        // Evenly matched teams: the winners gain, the losers lose, symmetrically within a team.
+        assert!(new_ratings[0][0].mu > BbtRating::new().mu);
+        assert!(new_ratings[0][1].mu > BbtRating::new().mu);
+        assert!(new_ratings[1][0].mu < BbtRating::new().mu);
+        assert!(new_ratings[1][1].mu < BbtRating::new().mu);
+        assert!((new_ratings[0][0].mu - new_ratings[0][1].mu).abs() < f64::EPSILON);
+
+        // Sigma shrinks towards a floor, but never collapses to zero thanks to tau.
+        assert!(new_ratings[0][0].sigma < BbtRating::new().sigma);
+        assert!(new_ratings[0][0].sigma > 0.0);
+    }
+
+    #[test]
+    fn test_bbt_three_teams_ranked() {
+        let first = vec![BbtRating::new()];
+        let second = vec![BbtRating::new()];
+        let third = vec![BbtRating::new()];
+
+        let new_ratings = bbt(&[first, second, third], &BbtConfig::new());
+
+        // Placing first against two equally rated opponents should gain the most, and placing
+        // last should lose the most.
+        assert!(new_ratings[0][0].mu > new_ratings[1][0].mu);
+        assert!(new_ratings[1][0].mu > new_ratings[2][0].mu);
+    }
+
+    #[test]
+    fn test_bbt_uneven_team_sizes() {
+        // A lone player, rated higher than either opponent, beats a pair whose summed
+        // strength (50.0) is greater than theirs (30.0).
+        let solo = vec![BbtRating {
+            mu: 30.0,
+            sigma: 25.0 / 3.0,
+        }];
+        let duo = vec![BbtRating::new(), BbtRating::new()];
+
+        let new_ratings = bbt(&[solo, duo], &BbtConfig::new());
+
+        assert!(new_ratings[0][0].mu > 30.0);
+        assert!(new_ratings[1][0].mu < BbtRating::new().mu);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::rating::EloRating;
+
+/// The K-factor, or development coefficient, used by [`crate::elo::elo`] to scale how much a
+/// single game can move a player's rating.
+///
+/// A single fixed K-factor treats every player the same, but most real rating systems vary it
+/// by a player's experience and rating, so that new players' ratings converge quickly while
+/// established players' ratings stay stable.
+#[derive(Copy, Clone, Debug)]
+pub enum KFactor {
+    /// A single fixed K-factor used for every player, regardless of experience or rating.
+    Fixed(f64),
+    /// The FIDE-style dynamic K-factor scheme.
+    ///
+    /// A player who is flagged as [`EloRating::pro`] or whose current rating is at or above
+    /// `pro_rating` uses `pro_k`. Otherwise, a player who has played fewer than
+    /// `provisional_games` games uses `provisional_k`, and everyone else uses `normal_k`.
+    ///
+    /// The [`EloRating::pro`] flag, and with it the permanence of `pro_k`, is set by
+    /// [`crate::elo::elo`] from [`EloConfig::pro_rating_threshold`], not from `pro_rating`.
+    Fide {
+        /// The K-factor used for a player's first `provisional_games` games.
+        provisional_k: f64,
+        /// The number of games a player is considered provisional for.
+        provisional_games: usize,
+        /// The K-factor used once a player is no longer provisional, but not yet a pro.
+        normal_k: f64,
+        /// The K-factor used for a player flagged as a pro or rated at or above `pro_rating`.
+        pro_k: f64,
+        /// The rating threshold at which a player becomes a pro.
+        pro_rating: f64,
+    },
+    /// A user-supplied function computing the K-factor from a player's current rating state.
+    Custom(fn(&EloRating) -> f64),
+}
+
+impl KFactor {
+    #[must_use]
+    /// The default FIDE-style dynamic K-factor scheme:
+    /// K=40 for a player's first 30 games, K=20 afterwards, and K=10 once they reach a rating
+    /// of 2400 or above.
+    pub const fn fide() -> Self {
+        Self::Fide {
+            provisional_k: 40.0,
+            provisional_games: 30,
+            normal_k: 20.0,
+            pro_k: 10.0,
+            pro_rating: 2400.0,
+        }
+    }
+
+    #[must_use]
+    /// Computes the K-factor that applies to `player` under this scheme.
+    pub fn value_for(&self, player: &EloRating) -> f64 {
+        match *self {
+            Self::Fixed(k) => k,
+            Self::Fide {
+                provisional_k,
+                provisional_games,
+                normal_k,
+                pro_k,
+                pro_rating,
+            } => {
+                if player.pro || player.rating >= pro_rating {
+                    pro_k
+                } else if player.games_played < provisional_games {
+                    provisional_k
+                } else {
+                    normal_k
+                }
+            }
+            Self::Custom(f) => f(player),
+        }
+    }
+}
+
+/// Constants used in the Elo calculation.
+#[derive(Copy, Clone, Debug)]
+pub struct EloConfig {
+    /// The K-factor scheme used to decide how much a game can move a player's rating.
+    ///
+    /// The higher the number, the more volatile the rating. By default this is fixed at 32.0,
+    /// but you can switch to [`KFactor::fide`] or your own [`KFactor::Custom`] scheme to vary
+    /// it by player experience and rating.
+    pub k_factor: KFactor,
+    /// The rating a player must reach to be flagged as a pro, see [`EloRating::pro`].
+    ///
+    /// By default this is 2400.0, mirroring the FIDE "pro" threshold.
+    pub pro_rating_threshold: f64,
+    /// The kappa parameter of the Davidson draw model, used by
+    /// [`crate::elo::draw_probability`] and friends to split the tie mass out of the logistic
+    /// expectation.
+    ///
+    /// A value of 0.0 means draws never happen. Higher values mean draws are more likely,
+    /// especially between closely rated players. By default this is 0.5.
+    pub draw_kappa: f64,
+}
+
+impl EloConfig {
+    #[must_use]
+    /// Initialize a new `EloConfig` with a fixed K-factor of 32.0 and a draw kappa of 0.5.
+    pub const fn new() -> Self {
+        Self {
+            k_factor: KFactor::Fixed(32.0),
+            pro_rating_threshold: 2400.0,
+            draw_kappa: 0.5,
+        }
+    }
+}
+
+impl Default for EloConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Constants used in the Glicko-2 calculation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Glicko2Config {
+    /// The system constant, tau, which constrains the volatility over time.
+    ///
+    /// Reasonable choices are between 0.3 and 1.2. Smaller values mean player volatility
+    /// changes more slowly; larger values make the system more reactive to upsets.
+    /// By default this is 0.5.
+    pub tau: f64,
+    /// The maximum draw probability [`crate::glicko2::draw_probability`] can return, reached
+    /// when two players' skill distributions fully overlap (equal rating and deviation).
+    ///
+    /// By default this is 0.5.
+    pub draw_scale: f64,
+}
+
+impl Glicko2Config {
+    #[must_use]
+    /// Initialize a new `Glicko2Config` with a tau value of 0.5 and a draw scale of 0.5.
+    pub const fn new() -> Self {
+        Self {
+            tau: 0.5,
+            draw_scale: 0.5,
+        }
+    }
+}
+
+impl Default for Glicko2Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Constants used in the Bradley-Terry (`bbt`) calculation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BbtConfig {
+    /// The skill class width, beta, which determines the variance of a player's performance
+    /// around their mean skill.
+    ///
+    /// Smaller values make outcomes more deterministic given the mu's involved; larger values
+    /// make upsets more likely. By default this is 25.0 / 6.0 (half the default sigma).
+    pub beta: f64,
+    /// The dynamic factor, tau, added back to every player's sigma after each update to stop
+    /// it from shrinking to zero and the system from becoming too confident over time.
+    ///
+    /// By default this is 25.0 / 300.0.
+    pub tau: f64,
+    /// The minimum fraction of a player's variance that is kept after an update, regardless of
+    /// how surprising the result was.
+    ///
+    /// By default this is 0.0001.
+    pub kappa: f64,
+}
+
+impl BbtConfig {
+    #[must_use]
+    /// Initialize a new `BbtConfig` with the default beta, tau and kappa.
+    pub const fn new() -> Self {
+        Self {
+            beta: 25.0 / 6.0,
+            tau: 25.0 / 300.0,
+            kappa: 0.0001,
+        }
+    }
+}
+
+impl Default for BbtConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
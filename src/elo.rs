@@ -7,12 +7,15 @@ use crate::{config::EloConfig, outcomes::Outcomes, rating::EloRating};
 /// The outcome of the match is in the perspective of `player_one`.
 /// This means `Outcomes::WIN` is a win for `player_one` and `Outcomes::LOSS` is a win for `player_two`.
 ///
+/// Each player's own K-factor is derived from their own rating state (games played, pro status)
+/// via `config.k_factor`, so two players in the same game can move by different amounts.
+///
 /// # Example
 /// ```
 /// use skillratings::{elo::elo, outcomes::Outcomes, rating::EloRating, config::EloConfig};
 ///
-/// let player_one = EloRating { rating: 1000.0 };
-/// let player_two = EloRating { rating: 1000.0 };
+/// let player_one = EloRating { rating: 1000.0, ..Default::default() };
+/// let player_two = EloRating { rating: 1000.0, ..Default::default() };
 ///
 /// let outcome = Outcomes::WIN;
 ///
@@ -41,17 +44,22 @@ pub fn elo(
         Outcomes::DRAW => 0.5,
     };
 
-    let one_new_elo = config.k.mul_add(o - one_expected, player_one.rating);
-    let two_new_elo = config
-        .k
-        .mul_add((1.0 - o) - two_expected, player_two.rating);
+    let k_one = config.k_factor.value_for(&player_one);
+    let k_two = config.k_factor.value_for(&player_two);
+
+    let one_new_elo = k_one.mul_add(o - one_expected, player_one.rating);
+    let two_new_elo = k_two.mul_add((1.0 - o) - two_expected, player_two.rating);
 
     (
         EloRating {
             rating: one_new_elo,
+            games_played: player_one.games_played + 1,
+            pro: player_one.pro || one_new_elo >= config.pro_rating_threshold,
         },
         EloRating {
             rating: two_new_elo,
+            games_played: player_two.games_played + 1,
+            pro: player_two.pro || two_new_elo >= config.pro_rating_threshold,
         },
     )
 }
@@ -76,7 +84,7 @@ pub fn elo(
 ///
 /// let new_player = elo_rating_period(
 ///     player,
-///     &vec![
+///     &[
 ///         (opponent1, Outcomes::WIN),
 ///         (opponent2, Outcomes::WIN),
 ///         (opponent3, Outcomes::WIN),
@@ -89,7 +97,7 @@ pub fn elo(
 #[must_use]
 pub fn elo_rating_period(
     player: EloRating,
-    results: &Vec<(EloRating, Outcomes)>,
+    results: &[(EloRating, Outcomes)],
     config: &EloConfig,
 ) -> EloRating {
     let mut player = player;
@@ -111,8 +119,8 @@ pub fn elo_rating_period(
 /// ```
 /// use skillratings::{elo::expected_score, rating::EloRating};
 ///
-/// let player_one = EloRating { rating: 1320.0 };
-/// let player_two = EloRating { rating: 1217.0 };
+/// let player_one = EloRating { rating: 1320.0, ..Default::default() };
+/// let player_two = EloRating { rating: 1217.0, ..Default::default() };
 ///
 /// let (winner_exp, loser_exp) = expected_score(player_one, player_two);
 ///
@@ -127,15 +135,98 @@ pub fn expected_score(player_one: EloRating, player_two: EloRating) -> (f64, f64
     )
 }
 
+/// The three terms of the Davidson draw model shared by [`win_probability`],
+/// [`draw_probability`] and [`loss_probability`]: `(one_term, two_term, draw_term)`, all over
+/// the same denominator.
+fn davidson_terms(
+    player_one: EloRating,
+    player_two: EloRating,
+    config: &EloConfig,
+) -> (f64, f64, f64) {
+    let t = 10_f64.powf((player_two.rating - player_one.rating) / 400.0);
+    let draw_term = config.draw_kappa * t.sqrt();
+
+    (1.0, t, draw_term)
+}
+
+/// Calculates the probability that `player_one` wins against `player_two`, taking the
+/// [`EloConfig::draw_kappa`] draw model into account.
+///
+/// Unlike [`expected_score`], which folds a draw into half a win, this returns the win
+/// probability alone; see also [`draw_probability`] and [`loss_probability`].
+///
+/// # Example
+/// ```
+/// use skillratings::{elo::win_probability, rating::EloRating, config::EloConfig};
+///
+/// let player_one = EloRating::new();
+/// let player_two = EloRating::new();
+///
+/// let win_prob = win_probability(player_one, player_two, &EloConfig::new());
+///
+/// assert!((win_prob - 0.4).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn win_probability(player_one: EloRating, player_two: EloRating, config: &EloConfig) -> f64 {
+    let (one_term, two_term, draw_term) = davidson_terms(player_one, player_two, config);
+    one_term / (one_term + two_term + draw_term)
+}
+
+/// Calculates the probability that the game between `player_one` and `player_two` ends in a
+/// draw, using the Davidson draw model configured by [`EloConfig::draw_kappa`].
+///
+/// # Example
+/// ```
+/// use skillratings::{elo::draw_probability, rating::EloRating, config::EloConfig};
+///
+/// let player_one = EloRating::new();
+/// let player_two = EloRating::new();
+///
+/// let draw_prob = draw_probability(player_one, player_two, &EloConfig::new());
+///
+/// assert!((draw_prob - 0.2).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn draw_probability(player_one: EloRating, player_two: EloRating, config: &EloConfig) -> f64 {
+    let (one_term, two_term, draw_term) = davidson_terms(player_one, player_two, config);
+    draw_term / (one_term + two_term + draw_term)
+}
+
+/// Calculates the probability that `player_one` loses against `player_two`, taking the
+/// [`EloConfig::draw_kappa`] draw model into account.
+///
+/// # Example
+/// ```
+/// use skillratings::{elo::loss_probability, rating::EloRating, config::EloConfig};
+///
+/// let player_one = EloRating::new();
+/// let player_two = EloRating::new();
+///
+/// let loss_prob = loss_probability(player_one, player_two, &EloConfig::new());
+///
+/// assert!((loss_prob - 0.4).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn loss_probability(player_one: EloRating, player_two: EloRating, config: &EloConfig) -> f64 {
+    win_probability(player_two, player_one, config)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::KFactor;
 
     #[test]
     fn test_elo() {
         let (winner_new_elo, loser_new_elo) = elo(
-            EloRating { rating: 1000.0 },
-            EloRating { rating: 1000.0 },
+            EloRating {
+                rating: 1000.0,
+                ..Default::default()
+            },
+            EloRating {
+                rating: 1000.0,
+                ..Default::default()
+            },
             Outcomes::WIN,
             &EloConfig::new(),
         );
@@ -143,8 +234,14 @@ mod tests {
         assert!((loser_new_elo.rating - 984.0).abs() < f64::EPSILON);
 
         let (winner_new_elo, loser_new_elo) = elo(
-            EloRating { rating: 1000.0 },
-            EloRating { rating: 1000.0 },
+            EloRating {
+                rating: 1000.0,
+                ..Default::default()
+            },
+            EloRating {
+                rating: 1000.0,
+                ..Default::default()
+            },
             Outcomes::LOSS,
             &EloConfig::new(),
         );
@@ -152,8 +249,14 @@ mod tests {
         assert!((loser_new_elo.rating - 1016.0).abs() < f64::EPSILON);
 
         let (winner_new_elo, loser_new_elo) = elo(
-            EloRating { rating: 1000.0 },
-            EloRating { rating: 1000.0 },
+            EloRating {
+                rating: 1000.0,
+                ..Default::default()
+            },
+            EloRating {
+                rating: 1000.0,
+                ..Default::default()
+            },
             Outcomes::DRAW,
             &EloConfig::new(),
         );
@@ -161,8 +264,14 @@ mod tests {
         assert!((loser_new_elo.rating - 1000.0).abs() < f64::EPSILON);
 
         let (winner_new_elo, loser_new_elo) = elo(
-            EloRating { rating: 500.0 },
-            EloRating { rating: 1500.0 },
+            EloRating {
+                rating: 500.0,
+                ..Default::default()
+            },
+            EloRating {
+                rating: 1500.0,
+                ..Default::default()
+            },
             Outcomes::WIN,
             &EloConfig::default(),
         );
@@ -180,7 +289,7 @@ mod tests {
 
         let new_player = elo_rating_period(
             player,
-            &vec![
+            &[
                 (opponent1, Outcomes::WIN),
                 (opponent2, Outcomes::WIN),
                 (opponent3, Outcomes::WIN),
@@ -201,8 +310,14 @@ mod tests {
         assert!((winner_expected - 0.5).abs() < f64::EPSILON);
         assert!((loser_expected - 0.5).abs() < f64::EPSILON);
 
-        let player_one = EloRating { rating: 2251.0 };
-        let player_two = EloRating { rating: 1934.0 };
+        let player_one = EloRating {
+            rating: 2251.0,
+            ..Default::default()
+        };
+        let player_two = EloRating {
+            rating: 1934.0,
+            ..Default::default()
+        };
 
         let (winner_expected, loser_expected) = expected_score(player_one, player_two);
 
@@ -211,4 +326,99 @@ mod tests {
 
         assert!((winner_expected + loser_expected - 1.0).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_win_draw_loss_probability() {
+        let player_one = EloRating::new();
+        let player_two = EloRating::new();
+        let config = EloConfig::new();
+
+        let win = win_probability(player_one, player_two, &config);
+        let draw = draw_probability(player_one, player_two, &config);
+        let loss = loss_probability(player_one, player_two, &config);
+
+        assert!((win - 0.4).abs() < 0.001);
+        assert!((draw - 0.2).abs() < 0.001);
+        assert!((loss - 0.4).abs() < 0.001);
+        assert!((win + draw + loss - 1.0).abs() < f64::EPSILON);
+
+        // No draws at all when kappa is zero: win/loss should fall back to expected_score.
+        let no_draw_config = EloConfig {
+            draw_kappa: 0.0,
+            ..Default::default()
+        };
+        let (expected_win, expected_loss) = expected_score(player_one, player_two);
+        assert!(
+            (win_probability(player_one, player_two, &no_draw_config) - expected_win).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (loss_probability(player_one, player_two, &no_draw_config) - expected_loss).abs()
+                < f64::EPSILON
+        );
+        assert!(draw_probability(player_one, player_two, &no_draw_config).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_elo_fide_k_factor_provisional_vs_established() {
+        let config = EloConfig {
+            k_factor: KFactor::fide(),
+            ..Default::default()
+        };
+
+        // A brand new player (0 games played) uses the provisional K of 40.0.
+        let newcomer = EloRating::new();
+        let established = EloRating {
+            rating: 1000.0,
+            games_played: 30,
+            pro: false,
+        };
+
+        let (newcomer_new, established_new) = elo(newcomer, established, Outcomes::WIN, &config);
+
+        // K=40 for the newcomer: 1000 + 40 * 0.5 = 1020.0
+        assert!((newcomer_new.rating - 1020.0).abs() < f64::EPSILON);
+        // K=20 for the established player: 1000 - 20 * 0.5 = 990.0
+        assert!((established_new.rating - 990.0).abs() < f64::EPSILON);
+
+        assert_eq!(newcomer_new.games_played, 1);
+        assert_eq!(established_new.games_played, 31);
+    }
+
+    #[test]
+    fn test_elo_fide_pro_status_is_permanent() {
+        let config = EloConfig {
+            k_factor: KFactor::fide(),
+            ..Default::default()
+        };
+
+        let rising_star = EloRating {
+            rating: 2385.0,
+            games_played: 0,
+            pro: false,
+        };
+        let evenly_matched = EloRating {
+            rating: 2385.0,
+            games_played: 0,
+            pro: false,
+        };
+
+        // Still provisional, so K=40.0 applies; winning an even match pushes the rating
+        // over the 2400.0 threshold.
+        let (pro_new, _) = elo(rising_star, evenly_matched, Outcomes::WIN, &config);
+        assert!(pro_new.rating >= 2400.0);
+        assert!(pro_new.pro);
+
+        // Even if a later loss drops them back under the threshold, they keep the lower K.
+        let underdog = EloRating {
+            rating: 1000.0,
+            games_played: 200,
+            pro: false,
+        };
+        let (pro_after_loss, _) = elo(pro_new, underdog, Outcomes::LOSS, &config);
+        assert!(pro_after_loss.pro);
+        assert!(pro_after_loss.rating < pro_new.rating);
+        // The drop is close to K=10.0 (the pro K-factor), not K=20.0 or K=40.0.
+        assert!((pro_new.rating - pro_after_loss.rating - 10.0).abs() < 0.1);
+    }
 }
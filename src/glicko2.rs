@@ -0,0 +1,382 @@
+use crate::{config::Glicko2Config, outcomes::Outcomes, rating::Glicko2Rating};
+
+/// The scaling factor between the public Glicko-2 scale (ratings around 1500.0) and the
+/// internal scale the algorithm actually operates on.
+const SCALE: f64 = 173.7178;
+
+/// Convergence tolerance used when solving for the new volatility.
+const CONVERGENCE_TOLERANCE: f64 = 0.000_001;
+
+/// Calculates a new Glicko-2 rating for a player from every game they played in a single
+/// rating period, for compatibility with the other algorithms.
+///
+/// Takes in a player and their results as a Vec of tuples containing the opponent and the
+/// outcome, and a [`Glicko2Config`].
+///
+/// All of the outcomes are from the perspective of `player`.
+/// This means `Outcomes::WIN` is a win for `player` and `Outcomes::LOSS` is a win for the opponent.
+///
+/// Unlike [`crate::elo::elo_rating_period`], the games in the period are **not** applied one
+/// after another: Glicko-2 folds every result in the period into a single update, computed
+/// from the player's rating, deviation and volatility as they stood at the start of the period.
+///
+/// If `results` is empty, the player's rating and volatility stay the same, but their rating
+/// deviation increases, modeling the growing uncertainty of an inactive player.
+///
+/// # Example
+/// ```
+/// use skillratings::{glicko2::glicko2_rating_period, outcomes::Outcomes, rating::Glicko2Rating, config::Glicko2Config};
+///
+/// let player = Glicko2Rating {
+///     rating: 1500.0,
+///     deviation: 200.0,
+///     volatility: 0.06,
+/// };
+///
+/// let opponent1 = Glicko2Rating {
+///     rating: 1400.0,
+///     deviation: 30.0,
+///     volatility: 0.06,
+/// };
+/// let opponent2 = Glicko2Rating {
+///     rating: 1550.0,
+///     deviation: 100.0,
+///     volatility: 0.06,
+/// };
+/// let opponent3 = Glicko2Rating {
+///     rating: 1700.0,
+///     deviation: 300.0,
+///     volatility: 0.06,
+/// };
+///
+/// let new_player = glicko2_rating_period(
+///     player,
+///     &[
+///         (opponent1, Outcomes::WIN),
+///         (opponent2, Outcomes::LOSS),
+///         (opponent3, Outcomes::LOSS),
+///     ],
+///     &Glicko2Config::new(),
+/// );
+///
+/// assert!((new_player.rating.round() - 1464.0).abs() < f64::EPSILON);
+/// assert!((new_player.deviation.round() - 152.0).abs() < f64::EPSILON);
+/// ```
+///
+/// # More
+/// [Glicko-2 paper by Mark Glickman](http://www.glicko.net/glicko/glicko2.pdf).
+#[must_use]
+pub fn glicko2_rating_period(
+    player: Glicko2Rating,
+    results: &[(Glicko2Rating, Outcomes)],
+    config: &Glicko2Config,
+) -> Glicko2Rating {
+    let mu = (player.rating - 1500.0) / SCALE;
+    let phi = player.deviation / SCALE;
+    let sigma = player.volatility;
+
+    if results.is_empty() {
+        let phi_star = phi.hypot(sigma);
+
+        return Glicko2Rating {
+            rating: player.rating,
+            deviation: phi_star * SCALE,
+            volatility: sigma,
+        };
+    }
+
+    let gs_es: Vec<(f64, f64, f64)> = results
+        .iter()
+        .map(|(opponent, outcome)| {
+            let mu_j = (opponent.rating - 1500.0) / SCALE;
+            let phi_j = opponent.deviation / SCALE;
+
+            let g_j = g(phi_j);
+            let e_j = e(mu, mu_j, g_j);
+            let s_j = match outcome {
+                Outcomes::WIN => 1.0,
+                Outcomes::LOSS => 0.0,
+                Outcomes::DRAW => 0.5,
+            };
+
+            (g_j, e_j, s_j)
+        })
+        .collect();
+
+    let v = 1.0
+        / gs_es
+            .iter()
+            .map(|(g_j, e_j, _)| g_j.powi(2) * e_j * (1.0 - e_j))
+            .sum::<f64>();
+
+    let delta = v * gs_es
+        .iter()
+        .map(|(g_j, e_j, s_j)| g_j * (s_j - e_j))
+        .sum::<f64>();
+
+    let new_sigma = new_volatility(delta, phi, v, sigma, config.tau);
+
+    let phi_star = (phi.powi(2) + new_sigma.powi(2)).sqrt();
+    let new_phi = 1.0 / (1.0 / phi_star.powi(2) + 1.0 / v).sqrt();
+    let new_mu = mu
+        + new_phi.powi(2)
+            * gs_es
+                .iter()
+                .map(|(g_j, e_j, s_j)| g_j * (s_j - e_j))
+                .sum::<f64>();
+
+    Glicko2Rating {
+        rating: new_mu.mul_add(SCALE, 1500.0),
+        deviation: new_phi * SCALE,
+        volatility: new_sigma,
+    }
+}
+
+/// The shared terms of the draw model used by [`win_probability`], [`draw_probability`] and
+/// [`loss_probability`]: `(draw_probability, expected_score)`.
+fn glicko2_terms(
+    player_one: Glicko2Rating,
+    player_two: Glicko2Rating,
+    config: &Glicko2Config,
+) -> (f64, f64) {
+    let mu_one = (player_one.rating - 1500.0) / SCALE;
+    let phi_one = player_one.deviation / SCALE;
+    let mu_two = (player_two.rating - 1500.0) / SCALE;
+    let phi_two = player_two.deviation / SCALE;
+
+    let combined_g = g((phi_one.powi(2) + phi_two.powi(2)).sqrt());
+    let expected_score = e(mu_one, mu_two, combined_g);
+
+    // The Bhattacharyya coefficient between the two players' skill distributions: it is 1.0
+    // when the distributions are identical and shrinks as the rating gap grows or the
+    // deviations narrow, so it peaks exactly where two evenly matched, well-established
+    // players would be expected to draw most often.
+    let variance_sum = phi_one.powi(2) + phi_two.powi(2);
+    let overlap = (2.0 * phi_one * phi_two / variance_sum).sqrt()
+        * (-(mu_one - mu_two).powi(2) / (4.0 * variance_sum)).exp();
+
+    (config.draw_scale * overlap, expected_score)
+}
+
+/// Calculates the probability that `player_one` wins against `player_two`, incorporating both
+/// players' rating deviation through [`Glicko2Config::draw_scale`].
+///
+/// # Example
+/// ```
+/// use skillratings::{glicko2::win_probability, rating::Glicko2Rating, config::Glicko2Config};
+///
+/// let player_one = Glicko2Rating::new();
+/// let player_two = Glicko2Rating::new();
+///
+/// let win_prob = win_probability(player_one, player_two, &Glicko2Config::new());
+///
+/// assert!((win_prob - 0.25).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn win_probability(
+    player_one: Glicko2Rating,
+    player_two: Glicko2Rating,
+    config: &Glicko2Config,
+) -> f64 {
+    let (draw, expected_score) = glicko2_terms(player_one, player_two, config);
+    (1.0 - draw) * expected_score
+}
+
+/// Calculates the probability that the game between `player_one` and `player_two` ends in a
+/// draw.
+///
+/// This peaks when the two players' skill distributions heavily overlap (similar rating and
+/// similar deviation) and shrinks as the rating gap grows or either player's deviation
+/// narrows around a rating far from the other's.
+///
+/// # Example
+/// ```
+/// use skillratings::{glicko2::draw_probability, rating::Glicko2Rating, config::Glicko2Config};
+///
+/// let player_one = Glicko2Rating::new();
+/// let player_two = Glicko2Rating::new();
+///
+/// let draw_prob = draw_probability(player_one, player_two, &Glicko2Config::new());
+///
+/// assert!((draw_prob - 0.5).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn draw_probability(
+    player_one: Glicko2Rating,
+    player_two: Glicko2Rating,
+    config: &Glicko2Config,
+) -> f64 {
+    glicko2_terms(player_one, player_two, config).0
+}
+
+/// Calculates the probability that `player_one` loses against `player_two`, incorporating both
+/// players' rating deviation through [`Glicko2Config::draw_scale`].
+///
+/// # Example
+/// ```
+/// use skillratings::{glicko2::loss_probability, rating::Glicko2Rating, config::Glicko2Config};
+///
+/// let player_one = Glicko2Rating::new();
+/// let player_two = Glicko2Rating::new();
+///
+/// let loss_prob = loss_probability(player_one, player_two, &Glicko2Config::new());
+///
+/// assert!((loss_prob - 0.25).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn loss_probability(
+    player_one: Glicko2Rating,
+    player_two: Glicko2Rating,
+    config: &Glicko2Config,
+) -> f64 {
+    let (draw, expected_score) = glicko2_terms(player_one, player_two, config);
+    (1.0 - draw) * (1.0 - expected_score)
+}
+
+/// The "impact" of an opponent's rating deviation on the outcome of a game.
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi.powi(2) / std::f64::consts::PI.powi(2)).sqrt()
+}
+
+/// The expected outcome of a game, given the impact of the opponent's deviation.
+fn e(mu: f64, mu_j: f64, g_phi_j: f64) -> f64 {
+    1.0 / (1.0 + (-g_phi_j * (mu - mu_j)).exp())
+}
+
+/// Solves for the new volatility using the Illinois algorithm (a variant of regula falsi),
+/// as described in the Glicko-2 paper.
+fn new_volatility(delta: f64, phi: f64, v: f64, sigma: f64, tau: f64) -> f64 {
+    let f = |x: f64| {
+        let ex = x.exp();
+        (ex * (delta.powi(2) - phi.powi(2) - v - ex)) / (2.0 * (phi.powi(2) + v + ex).powi(2))
+            - (x - (sigma.powi(2)).ln()) / tau.powi(2)
+    };
+
+    let a = (sigma.powi(2)).ln();
+    let mut big_a = a;
+    let mut big_b = if delta.powi(2) > phi.powi(2) + v {
+        (delta.powi(2) - phi.powi(2) - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * tau) < 0.0 {
+            k += 1.0;
+        }
+        a - k * tau
+    };
+
+    let mut f_a = f(big_a);
+    let mut f_b = f(big_b);
+
+    while (big_b - big_a).abs() > CONVERGENCE_TOLERANCE {
+        let c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+        let f_c = f(c);
+
+        if f_c * f_b < 0.0 {
+            big_a = big_b;
+            f_a = f_b;
+        } else {
+            f_a /= 2.0;
+        }
+
+        big_b = c;
+        f_b = f_c;
+    }
+
+    (big_a / 2.0).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glicko2_rating_period_worked_example() {
+        // This is the worked example from Glickman's Glicko-2 paper.
+        let player = Glicko2Rating {
+            rating: 1500.0,
+            deviation: 200.0,
+            volatility: 0.06,
+        };
+
+        let opponent1 = Glicko2Rating {
+            rating: 1400.0,
+            deviation: 30.0,
+            volatility: 0.06,
+        };
+        let opponent2 = Glicko2Rating {
+            rating: 1550.0,
+            deviation: 100.0,
+            volatility: 0.06,
+        };
+        let opponent3 = Glicko2Rating {
+            rating: 1700.0,
+            deviation: 300.0,
+            volatility: 0.06,
+        };
+
+        let new_player = glicko2_rating_period(
+            player,
+            &[
+                (opponent1, Outcomes::WIN),
+                (opponent2, Outcomes::LOSS),
+                (opponent3, Outcomes::LOSS),
+            ],
+            &Glicko2Config::new(),
+        );
+
+        assert!((new_player.rating - 1464.06).abs() < 0.01);
+        assert!((new_player.deviation - 151.52).abs() < 0.01);
+        assert!((new_player.volatility - 0.06).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_glicko2_rating_period_empty_results_grows_deviation() {
+        let player = Glicko2Rating {
+            rating: 1500.0,
+            deviation: 200.0,
+            volatility: 0.06,
+        };
+
+        let new_player = glicko2_rating_period(player, &[], &Glicko2Config::new());
+
+        assert!((new_player.rating - player.rating).abs() < f64::EPSILON);
+        assert!((new_player.volatility - player.volatility).abs() < f64::EPSILON);
+        assert!(new_player.deviation > player.deviation);
+    }
+
+    #[test]
+    fn test_win_draw_loss_probability() {
+        let player_one = Glicko2Rating::new();
+        let player_two = Glicko2Rating::new();
+        let config = Glicko2Config::new();
+
+        let win = win_probability(player_one, player_two, &config);
+        let draw = draw_probability(player_one, player_two, &config);
+        let loss = loss_probability(player_one, player_two, &config);
+
+        // Two identical, fully-overlapping distributions: win and loss are equal, and the
+        // draw chance is exactly the configured maximum.
+        assert!((win - 0.25).abs() < 0.001);
+        assert!((draw - 0.5).abs() < 0.001);
+        assert!((loss - 0.25).abs() < 0.001);
+        assert!((win + draw + loss - 1.0).abs() < f64::EPSILON);
+
+        // A much stronger, well-established player should win far more often and draw rarely.
+        let strong = Glicko2Rating {
+            rating: 2000.0,
+            deviation: 30.0,
+            volatility: 0.06,
+        };
+        let weak = Glicko2Rating {
+            rating: 1200.0,
+            deviation: 30.0,
+            volatility: 0.06,
+        };
+
+        let strong_win = win_probability(strong, weak, &config);
+        let strong_draw = draw_probability(strong, weak, &config);
+
+        assert!(strong_win > 0.9);
+        assert!(strong_draw < draw);
+    }
+}
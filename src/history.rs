@@ -0,0 +1,272 @@
+use crate::{config::EloConfig, elo::elo, outcomes::Outcomes, rating::EloRating};
+
+/// A player's unique identifier within a [`Registry`].
+pub type PlayerId = usize;
+
+/// A single recorded game between two players, including the rating change it produced.
+///
+/// Taken together, a player's [`Player::history`] is enough to audit or replay every rating
+/// change they have ever gone through.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Game {
+    /// The player whose perspective `outcome` is from.
+    pub player_one: PlayerId,
+    /// The opponent.
+    pub player_two: PlayerId,
+    /// The outcome of the game, from `player_one`'s perspective.
+    pub outcome: Outcomes,
+    /// The rating change this game produced for `player_one`.
+    pub player_one_delta: f64,
+    /// The rating change this game produced for `player_two`.
+    pub player_two_delta: f64,
+}
+
+/// A player with a current [`EloRating`] and the full history of games that produced it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Player {
+    /// The player's unique identifier within their [`Registry`].
+    pub id: PlayerId,
+    /// The player's display name.
+    pub name: String,
+    /// The player's current rating.
+    pub rating: EloRating,
+    /// The number of games this player has completed.
+    pub games_played: usize,
+    /// Every game this player has played, in the order they were played.
+    pub history: Vec<Game>,
+}
+
+impl Player {
+    /// Creates a new player with a fresh [`EloRating`] and no game history.
+    fn new(id: PlayerId, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            rating: EloRating::new(),
+            games_played: 0,
+            history: Vec::new(),
+        }
+    }
+}
+
+/// A registry of [`Player`]s that tracks every game played between them, so that callers don't
+/// have to thread ratings through [`crate::elo::elo`] and track game history themselves.
+///
+/// This turns the raw `elo` function into something usable as the backend of an actual ladder:
+/// [`Registry::leaderboard`] ranks every player by rating, and [`Registry::recompute`] rebuilds
+/// every player's rating from scratch by replaying the stored game log. The registry has no
+/// method to edit the stored games or its [`EloConfig`], so a replay reproduces the current
+/// ratings.
+///
+/// # Example
+/// ```
+/// use skillratings::{history::Registry, outcomes::Outcomes, config::EloConfig};
+///
+/// let mut registry = Registry::new(EloConfig::new());
+///
+/// let alice = registry.add_player("Alice");
+/// let bob = registry.add_player("Bob");
+///
+/// registry.record_game(alice, bob, Outcomes::WIN);
+///
+/// let leaderboard = registry.leaderboard();
+/// assert_eq!(leaderboard[0].id, alice);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Registry {
+    players: Vec<Player>,
+    games: Vec<Game>,
+    config: EloConfig,
+}
+
+impl Registry {
+    #[must_use]
+    /// Creates a new, empty `Registry` that rates games using `config`.
+    pub fn new(config: EloConfig) -> Self {
+        Self {
+            players: Vec::new(),
+            games: Vec::new(),
+            config,
+        }
+    }
+
+    /// Adds a new player to the registry with a fresh [`EloRating`], returning their
+    /// [`PlayerId`].
+    pub fn add_player(&mut self, name: impl Into<String>) -> PlayerId {
+        let id = self.players.len();
+        self.players.push(Player::new(id, name));
+        id
+    }
+
+    #[must_use]
+    /// Returns the player with the given id, if they exist in this registry.
+    pub fn player(&self, id: PlayerId) -> Option<&Player> {
+        self.players.get(id)
+    }
+
+    /// Records a game between `player_one` and `player_two`, rating it with [`crate::elo::elo`]
+    /// and updating both players' rating, games played, and history.
+    ///
+    /// `outcome` is from `player_one`'s perspective, same as in [`crate::elo::elo`].
+    ///
+    /// # Panics
+    /// Panics if either `player_one` or `player_two` is not a valid [`PlayerId`] in this
+    /// registry, or if they are the same player.
+    pub fn record_game(&mut self, player_one: PlayerId, player_two: PlayerId, outcome: Outcomes) {
+        let game = self.apply_game(player_one, player_two, outcome);
+        self.games.push(game);
+    }
+
+    /// Rebuilds every player's rating and history from scratch by resetting everyone to a
+    /// fresh [`EloRating`] and replaying the stored game log, in order, through the registry's
+    /// current [`EloConfig`].
+    pub fn recompute(&mut self) {
+        for player in &mut self.players {
+            player.rating = EloRating::new();
+            player.games_played = 0;
+            player.history.clear();
+        }
+
+        let games = std::mem::take(&mut self.games);
+        self.games = games
+            .into_iter()
+            .map(|game| self.apply_game(game.player_one, game.player_two, game.outcome))
+            .collect();
+    }
+
+    #[must_use]
+    /// Returns every player in the registry, ranked from highest to lowest rating.
+    pub fn leaderboard(&self) -> Vec<&Player> {
+        let mut players: Vec<&Player> = self.players.iter().collect();
+        players.sort_by(|a, b| {
+            b.rating
+                .rating
+                .partial_cmp(&a.rating.rating)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        players
+    }
+
+    /// Rates a game between `player_one` and `player_two` with the registry's [`EloConfig`],
+    /// updates both players in place, and returns the recorded [`Game`] with its deltas filled
+    /// in.
+    ///
+    /// # Panics
+    /// Panics if either player id is not valid in this registry, or if they are the same
+    /// player.
+    fn apply_game(
+        &mut self,
+        player_one: PlayerId,
+        player_two: PlayerId,
+        outcome: Outcomes,
+    ) -> Game {
+        assert!(
+            player_one != player_two,
+            "a player cannot play a game against themselves"
+        );
+
+        let before_one = self.players[player_one].rating;
+        let before_two = self.players[player_two].rating;
+
+        let (after_one, after_two) = elo(before_one, before_two, outcome, &self.config);
+
+        let game = Game {
+            player_one,
+            player_two,
+            outcome,
+            player_one_delta: after_one.rating - before_one.rating,
+            player_two_delta: after_two.rating - before_two.rating,
+        };
+
+        let one = &mut self.players[player_one];
+        one.rating = after_one;
+        one.games_played += 1;
+        one.history.push(game.clone());
+
+        let two = &mut self.players[player_two];
+        two.rating = after_two;
+        two.games_played += 1;
+        two.history.push(game.clone());
+
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "cannot play a game against themselves")]
+    fn test_registry_record_game_rejects_self_play() {
+        let mut registry = Registry::new(EloConfig::new());
+        let alice = registry.add_player("Alice");
+
+        registry.record_game(alice, alice, Outcomes::WIN);
+    }
+
+    #[test]
+    fn test_registry_record_game_updates_both_players() {
+        let mut registry = Registry::new(EloConfig::new());
+
+        let alice = registry.add_player("Alice");
+        let bob = registry.add_player("Bob");
+
+        registry.record_game(alice, bob, Outcomes::WIN);
+
+        assert!(registry.player(alice).unwrap().rating.rating > 1000.0);
+        assert!(registry.player(bob).unwrap().rating.rating < 1000.0);
+        assert_eq!(registry.player(alice).unwrap().games_played, 1);
+        assert_eq!(registry.player(bob).unwrap().games_played, 1);
+        assert_eq!(registry.player(alice).unwrap().history.len(), 1);
+        assert_eq!(registry.player(bob).unwrap().history.len(), 1);
+
+        let recorded = &registry.player(alice).unwrap().history[0];
+        assert!(recorded.player_one_delta > 0.0);
+        assert!(recorded.player_two_delta < 0.0);
+    }
+
+    #[test]
+    fn test_registry_leaderboard_is_sorted_by_rating() {
+        let mut registry = Registry::new(EloConfig::new());
+
+        let alice = registry.add_player("Alice");
+        let bob = registry.add_player("Bob");
+        let carol = registry.add_player("Carol");
+
+        registry.record_game(alice, bob, Outcomes::WIN);
+        registry.record_game(alice, carol, Outcomes::WIN);
+
+        let leaderboard = registry.leaderboard();
+
+        assert_eq!(leaderboard[0].id, alice);
+        assert!(leaderboard[0].rating.rating >= leaderboard[1].rating.rating);
+        assert!(leaderboard[1].rating.rating >= leaderboard[2].rating.rating);
+    }
+
+    #[test]
+    fn test_registry_recompute_matches_replayed_history() {
+        let mut registry = Registry::new(EloConfig::new());
+
+        let alice = registry.add_player("Alice");
+        let bob = registry.add_player("Bob");
+        let carol = registry.add_player("Carol");
+
+        registry.record_game(alice, bob, Outcomes::WIN);
+        registry.record_game(bob, carol, Outcomes::DRAW);
+        registry.record_game(alice, carol, Outcomes::LOSS);
+
+        let before = registry.leaderboard();
+        let before_ratings: Vec<f64> = before.iter().map(|p| p.rating.rating).collect();
+
+        registry.recompute();
+
+        let after = registry.leaderboard();
+        let after_ratings: Vec<f64> = after.iter().map(|p| p.rating.rating).collect();
+
+        assert_eq!(before_ratings, after_ratings);
+        assert_eq!(registry.player(alice).unwrap().games_played, 2);
+        assert_eq!(registry.player(bob).unwrap().games_played, 2);
+        assert_eq!(registry.player(carol).unwrap().games_played, 2);
+    }
+}
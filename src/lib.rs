@@ -0,0 +1,20 @@
+//! Skillratings provides functions for calculating the ratings of players, and for predicting
+//! the outcome of games between them, for a collection of rating systems.
+//!
+//! Currently implemented:
+//! - The [Elo rating system](elo), optionally with a FIDE-style dynamic K-factor and
+//!   win/draw/loss match prediction.
+//! - The [Glicko-2 rating system](glicko2), also with win/draw/loss match prediction.
+//! - The [Bradley-Terry rating system](bbt), for team and multiplayer games.
+//!
+//! The optional [`history`] module adds a stateful [`history::Registry`] of players and game
+//! history on top of the raw Elo functions, for callers who don't want to track ratings and
+//! results themselves.
+
+pub mod bbt;
+pub mod config;
+pub mod elo;
+pub mod glicko2;
+pub mod history;
+pub mod outcomes;
+pub mod rating;
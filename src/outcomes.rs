@@ -0,0 +1,12 @@
+/// Enum to represent the outcome of a match.
+///
+/// Used in [`crate::elo::elo`] and many other functions in this crate.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Outcomes {
+    /// A win, from the perspective of the player in question.
+    WIN,
+    /// A loss, from the perspective of the player in question.
+    LOSS,
+    /// A draw.
+    DRAW,
+}
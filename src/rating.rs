@@ -0,0 +1,109 @@
+/// The Elo rating of a player.
+///
+/// The default rating is 1000.0, with no games played and no pro status.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct EloRating {
+    /// The player's Elo rating number, by default 1000.0.
+    pub rating: f64,
+    /// The number of games the player has played; [`crate::elo::elo`] adds one per game, so
+    /// [`crate::elo::elo_rating_period`] adds one per result.
+    ///
+    /// Used by [`crate::config::KFactor::Fide`] to decide whether a player is still
+    /// "provisional" and should move through the rating system more quickly.
+    pub games_played: usize,
+    /// Whether the player has ever reached the "pro" rating threshold.
+    ///
+    /// Once a player becomes a pro, they keep this status (and the lower K-factor that comes
+    /// with it) permanently, even if their rating later drops back below the threshold.
+    pub pro: bool,
+}
+
+impl EloRating {
+    #[must_use]
+    /// Initialize a new `EloRating` with a rating of 1000.0, zero games played, and no pro status.
+    pub fn new() -> Self {
+        Self {
+            rating: 1000.0,
+            games_played: 0,
+            pro: false,
+        }
+    }
+}
+
+impl Default for EloRating {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The Glicko-2 rating of a player.
+///
+/// In addition to a rating, Glicko-2 tracks a rating deviation (how confident the system is
+/// in that rating) and a volatility (how erratic the player's results have been).
+///
+/// The default rating is 1500.0, with a deviation of 350.0 and a volatility of 0.06.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Glicko2Rating {
+    /// The player's Glicko-2 rating number, by default 1500.0.
+    pub rating: f64,
+    /// The player's rating deviation, by default 350.0.
+    ///
+    /// The lower this is, the more confident the system is in the player's rating.
+    pub deviation: f64,
+    /// The player's rating volatility, by default 0.06.
+    ///
+    /// This represents the degree of expected fluctuation in the player's rating.
+    pub volatility: f64,
+}
+
+impl Glicko2Rating {
+    #[must_use]
+    /// Initialize a new `Glicko2Rating` with a rating of 1500.0, a deviation of 350.0,
+    /// and a volatility of 0.06.
+    pub fn new() -> Self {
+        Self {
+            rating: 1500.0,
+            deviation: 350.0,
+            volatility: 0.06,
+        }
+    }
+}
+
+impl Default for Glicko2Rating {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The Bradley-Terry rating of a player, for use in team and multiplayer games.
+///
+/// Like [`Glicko2Rating`], a player's skill is modeled as a Gaussian: `mu` is the mean skill
+/// estimate and `sigma` is the uncertainty around it.
+///
+/// The default rating is 25.0, with a sigma of 25.0 / 3.0.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct BbtRating {
+    /// The player's skill mean, by default 25.0.
+    pub mu: f64,
+    /// The player's skill uncertainty, by default 25.0 / 3.0.
+    ///
+    /// The lower this is, the more confident the system is in the player's mean skill estimate.
+    pub sigma: f64,
+}
+
+impl BbtRating {
+    #[must_use]
+    /// Initialize a new `BbtRating` with a mu of 25.0 and a sigma of 25.0 / 3.0.
+    pub fn new() -> Self {
+        Self {
+            mu: 25.0,
+            sigma: 25.0 / 3.0,
+        }
+    }
+}
+
+impl Default for BbtRating {
+    fn default() -> Self {
+        Self::new()
+    }
+}